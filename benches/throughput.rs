@@ -0,0 +1,32 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ef80escape::{bytes_to_str, str_to_bytes};
+
+// Mostly-clean input with a sparse sprinkling of `0xEE` bytes, the case the
+// `memchr`-accelerated scan is meant to speed up.
+fn sparse_input(len: usize, every: usize) -> Vec<u8> {
+    let mut data = vec![b'a'; len];
+    let mut i = every;
+    while i < len {
+        data[i] = 0xee;
+        i += every;
+    }
+    data
+}
+
+fn bench(c: &mut Criterion) {
+    let data = sparse_input(1 << 20, 4096);
+    let encoded = bytes_to_str(&data).into_owned();
+
+    let mut group = c.benchmark_group("sparse");
+    group.throughput(Throughput::Bytes(data.len() as u64));
+    group.bench_function("bytes_to_str", |b| {
+        b.iter(|| bytes_to_str(black_box(&data)))
+    });
+    group.bench_function("str_to_bytes", |b| {
+        b.iter(|| str_to_bytes(black_box(&encoded)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -41,6 +41,8 @@
 
 use std::borrow::Cow;
 
+use memchr::memchr;
+
 // U+0800-U+FFFF: 1110xxxx 10xxxxxx 10xxxxxx
 // U+EF00: EE BC 80
 // U+EF80: EE BE 80
@@ -102,52 +104,25 @@ use std::borrow::Cow;
 /// `U+EF00`..`U+EFFF` is in Unicode Private Use Area. They can be
 /// encoded in UTF-8.
 pub fn bytes_to_str(data: &[u8]) -> Cow<str> {
-    let rest: &mut &[u8] = &mut &data[..];
-    let mut result = Vec::new();
-
-    // Extend `out` with escaped UTF-8 bytes.
-    fn extend_escaped_utf8(utf8_bytes: &[u8], out: &mut Vec<u8>) {
-        for (i, &b) in utf8_bytes.iter().enumerate() {
-            if b == 0xee {
-                if let (Some(&b1), Some(&b2)) = (utf8_bytes.get(i + 1), utf8_bytes.get(i + 2)) {
-                    // U+EE00, U+EF80..U+EFFF
-                    if need_escape(b1, b2) {
-                        // Push U+EF00 as escape prefix.
-                        out.extend_from_slice(&[0xee, 0xbc, 0x80]);
-                    }
-                }
-            }
-            out.push(b);
+    if !data.contains(&0xee) {
+        if let Ok(s) = std::str::from_utf8(data) {
+            // Zero-copy fast path.
+            return Cow::Borrowed(s);
         }
     }
-
-    while !rest.is_empty() {
-        match std::str::from_utf8(rest) {
-            Ok(s) => {
-                if result.is_empty() && !rest.contains(&0xee) {
-                    // Zero-copy fast path.
-                    return Cow::Borrowed(s);
-                }
-                extend_escaped_utf8(rest, &mut result);
-                break;
-            }
-            Err(e) => {
-                let l = e.valid_up_to();
-                extend_escaped_utf8(&rest[..l], &mut result);
-                let b = rest[l];
-                result.extend_from_slice(&[0xee, 0xbe + ((b ^ 128) >> 6), (b | 0x40) ^ 0x40]);
-                *rest = &rest[l + 1..];
+    let mut result = String::with_capacity(data.len());
+    for chunk in chunks(data) {
+        match chunk {
+            Chunk::Valid(s) => result.push_str(s),
+            Chunk::Escape(b) => result.push(pua_char(b)),
+            Chunk::Conflict(s) => {
+                // Prefix with U+EF00 so the conflicting character round-trips.
+                result.push('\u{ef00}');
+                result.push_str(s);
             }
         }
     }
-
-    let s = if cfg!(debug_assertions) {
-        String::from_utf8(result).unwrap()
-    } else {
-        // safety: code above only appends valid utf-8 to result.
-        unsafe { String::from_utf8_unchecked(result) }
-    };
-    Cow::Owned(s)
+    Cow::Owned(result)
 }
 
 /// Inverse of [`bytes_to_str`].
@@ -212,34 +187,49 @@ pub fn str_to_bytes<'a>(data: &'a str) -> Cow<'a, [u8]> {
     }
     let mut result = Vec::with_capacity(data.len());
     let mut escaped = false;
-    let mut iter = data.iter().enumerate();
-    while let Some((i, &b)) = iter.next() {
-        if b == 0xee {
-            if let (Some(&b1), Some(&b2)) = (data.get(i + 1), data.get(i + 2)) {
-                if need_escape(b1, b2) {
-                    match (b1, escaped) {
-                        (0xbc, false) => {
-                            escaped = true;
-                        }
-                        (_, true) => {
-                            result.extend_from_slice(&[b, b1, b2]);
-                            escaped = false;
-                        }
-                        (_, false) => {
-                            let v = ((b1 & 3) << 6) | (b2 & 63);
-                            result.push(v);
-                        }
+    decode_into(data, &mut escaped, &mut result);
+    Cow::Owned(result)
+}
+
+// Decode `data` into `result`, carrying the `U+EF00` escape flag across calls.
+// Any `0xEE` in `data` is assumed to have its two continuation bytes present
+// (callers that may split mid-character must hold back an incomplete tail).
+fn decode_into(data: &[u8], escaped: &mut bool, result: &mut Vec<u8>) {
+    let mut start = 0;
+    while let Some(rel) = memchr(0xee, &data[start..]) {
+        let i = start + rel;
+        if i > start {
+            // Bulk-copy the clean run that contains no `0xEE`.
+            result.extend_from_slice(&data[start..i]);
+            *escaped = false;
+        }
+        if let (Some(&b1), Some(&b2)) = (data.get(i + 1), data.get(i + 2)) {
+            if need_escape(b1, b2) {
+                match (b1, *escaped) {
+                    (0xbc, false) => {
+                        *escaped = true;
+                    }
+                    (_, true) => {
+                        result.extend_from_slice(&[0xee, b1, b2]);
+                        *escaped = false;
+                    }
+                    (_, false) => {
+                        let v = ((b1 & 3) << 6) | (b2 & 63);
+                        result.push(v);
                     }
-                    iter.next();
-                    iter.next();
-                    continue;
                 }
+                start = i + 3;
+                continue;
             }
         }
-        escaped = false;
-        result.push(b);
+        *escaped = false;
+        result.push(0xee);
+        start = i + 1;
+    }
+    if start < data.len() {
+        result.extend_from_slice(&data[start..]);
+        *escaped = false;
     }
-    Cow::Owned(result)
 }
 
 // Test if bytes [0xEE, b1, b2] matches unicode U+EE00, U+EF80..U+EFFF.
@@ -248,6 +238,542 @@ fn need_escape(b1: u8, b2: u8) -> bool {
     (b1 == 0xbc && b2 == 0x80) || ((b1 | 1) == 0xbf && b2 >= 0x80 && b2 <= 0xbf)
 }
 
+// PUA character `U+EF80`..`U+EFFF` encoding a non-UTF-8 byte `b` (>= 128).
+#[inline]
+fn pua_char(b: u8) -> char {
+    // safety: b >= 0x80, so 0xEF00 | b is in U+EF80..U+EFFF, a valid char.
+    debug_assert!(b >= 0x80);
+    unsafe { char::from_u32_unchecked(0xef00 | b as u32) }
+}
+
+/// A classified segment of input produced by [`chunks`].
+///
+/// Driving this enum lets callers send encoded output into their own sink — a
+/// [`fmt::Write`](std::fmt::Write), a serializer, a hasher — without the
+/// intermediate allocation that [`bytes_to_str`] makes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Chunk<'a> {
+    /// A maximal run of valid UTF-8 that needs no escaping: it contains
+    /// neither a non-UTF-8 byte nor a conflicting `U+EF00` or
+    /// `U+EF80`..`U+EFFF` character.
+    /// It maps to itself.
+    Valid(&'a str),
+    /// A single non-UTF-8 byte, which maps to the PUA character
+    /// `U+EF80`..`U+EFFF` for that byte.
+    Escape(u8),
+    /// A conflicting `U+EF00` or `U+EF80`..`U+EFFF` character from the input,
+    /// which maps to itself behind a `U+EF00` escape prefix.
+    Conflict(&'a str),
+}
+
+/// Splits `data` into classified [`Chunk`]s without allocating.
+///
+/// This is the single source of truth behind [`bytes_to_str`]: mapping
+/// [`Chunk::Valid`] to itself, [`Chunk::Escape`] to its PUA character, and
+/// [`Chunk::Conflict`] to a `U+EF00` prefix followed by the character
+/// reproduces [`bytes_to_str`] exactly. Advanced callers can instead stream
+/// the encoding straight into a sink:
+///
+/// ```
+/// # use ef80escape::{chunks, Chunk};
+/// use std::fmt::Write;
+/// let mut out = String::new();
+/// for chunk in chunks(b"a\xff") {
+///     match chunk {
+///         Chunk::Valid(s) => out.push_str(s),
+///         Chunk::Escape(b) => write!(out, "{}", char::from_u32(0xef00 | b as u32).unwrap()).unwrap(),
+///         Chunk::Conflict(s) => write!(out, "\u{ef00}{}", s).unwrap(),
+///     }
+/// }
+/// assert_eq!(out, "a\u{efff}");
+/// ```
+pub fn chunks(data: &[u8]) -> Chunks<'_> {
+    Chunks {
+        data,
+        pos: 0,
+        valid_end: 0,
+    }
+}
+
+/// Iterator over classified [`Chunk`]s. Created by [`chunks`].
+#[derive(Clone, Debug)]
+pub struct Chunks<'a> {
+    data: &'a [u8],
+    pos: usize,
+    // `data[pos..valid_end]` is known to be valid UTF-8. Validating the whole
+    // maximal run once (instead of on every `next`) keeps scanning linear even
+    // when the input is dense in conflicting characters.
+    valid_end: usize,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = Chunk<'a>;
+
+    fn next(&mut self) -> Option<Chunk<'a>> {
+        if self.pos >= self.data.len() {
+            return None;
+        }
+        if self.pos >= self.valid_end {
+            // The cursor is past the validated region; extend it.
+            let rest = &self.data[self.pos..];
+            let valid = match std::str::from_utf8(rest) {
+                Ok(_) => rest.len(),
+                Err(e) => e.valid_up_to(),
+            };
+            if valid == 0 {
+                let b = rest[0];
+                self.pos += 1;
+                return Some(Chunk::Escape(b));
+            }
+            self.valid_end = self.pos + valid;
+        }
+        // Inside a known-valid region: find the next conflicting character.
+        let region = &self.data[self.pos..self.valid_end];
+        let mut end = region.len();
+        let mut search = 0;
+        while let Some(rel) = memchr(0xee, &region[search..]) {
+            let i = search + rel;
+            // safety: `region` is valid UTF-8, so a `0xEE` lead byte always has
+            // its two continuation bytes present.
+            if need_escape(region[i + 1], region[i + 2]) {
+                end = i;
+                break;
+            }
+            search = i + 1;
+        }
+        if end == 0 {
+            // A conflicting character sits at the cursor.
+            let s = unsafe { std::str::from_utf8_unchecked(&region[..3]) };
+            self.pos += 3;
+            Some(Chunk::Conflict(s))
+        } else {
+            let s = unsafe { std::str::from_utf8_unchecked(&region[..end]) };
+            self.pos += end;
+            Some(Chunk::Valid(s))
+        }
+    }
+}
+
+// Number of trailing bytes of `buf` that form an incomplete (but possibly
+// valid) multi-byte UTF-8 prefix. Splitting `buf` before these bytes never
+// cuts a complete character in half, so the earlier part can be processed
+// independently. The result is always in `0..=3`.
+fn incomplete_tail_len(buf: &[u8]) -> usize {
+    let len = buf.len();
+    for back in 1..=std::cmp::min(3, len) {
+        let b = buf[len - back];
+        if b < 0x80 {
+            // ASCII: a complete character, nothing is pending.
+            return 0;
+        }
+        if b >= 0xc0 {
+            // Lead byte of a `need`-byte sequence.
+            let need = if b >= 0xf0 {
+                4
+            } else if b >= 0xe0 {
+                3
+            } else {
+                2
+            };
+            return if back < need { back } else { 0 };
+        }
+        // Continuation byte: keep walking back towards the lead byte.
+    }
+    0
+}
+
+/// Incremental version of [`bytes_to_str`] for data arriving in arbitrary
+/// pieces (network reads, pipe output) without buffering the whole stream.
+///
+/// Feed each piece to [`push`](Self::push) and call [`finish`](Self::finish)
+/// at end of stream. The concatenation of every returned fragment is
+/// byte-identical to calling [`bytes_to_str`] on the full input at once.
+///
+/// ```
+/// # use ef80escape::{Encoder, bytes_to_str};
+/// let data = b"\xffabc\xe4\xb8\xad";
+/// let mut enc = Encoder::new();
+/// let mut out = String::new();
+/// out.push_str(&enc.push(&data[..2]));
+/// out.push_str(&enc.push(&data[2..5]));
+/// out.push_str(&enc.push(&data[5..]));
+/// out.push_str(&enc.finish());
+/// assert_eq!(out, bytes_to_str(data));
+/// ```
+///
+/// # Retained bytes
+///
+/// To decide escaping, [`bytes_to_str`] needs to see a whole UTF-8 sequence
+/// (`std::str::from_utf8` may stop mid-sequence, and the `0xEE` look-ahead
+/// inspects two following bytes). The encoder therefore retains at most **3**
+/// trailing bytes between calls — an incomplete UTF-8 prefix whose escaping
+/// cannot yet be decided. A dangling incomplete sequence left at
+/// [`finish`](Self::finish) is invalid UTF-8 and is escaped byte-by-byte.
+#[derive(Clone, Debug, Default)]
+pub struct Encoder {
+    pending: Vec<u8>,
+}
+
+impl Encoder {
+    /// Creates an encoder with no retained bytes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds the next `chunk` of bytes and returns the encoded fragment that
+    /// can be decided so far. Up to 3 trailing bytes may be retained.
+    pub fn push<'a>(&mut self, chunk: &'a [u8]) -> Cow<'a, str> {
+        if self.pending.is_empty() {
+            let n = chunk.len() - incomplete_tail_len(chunk);
+            let out = bytes_to_str(&chunk[..n]);
+            self.pending.extend_from_slice(&chunk[n..]);
+            return out;
+        }
+        self.pending.extend_from_slice(chunk);
+        let n = self.pending.len() - incomplete_tail_len(&self.pending);
+        let out = bytes_to_str(&self.pending[..n]).into_owned();
+        self.pending.drain(..n);
+        Cow::Owned(out)
+    }
+
+    /// Flushes any retained bytes and returns the final fragment. A dangling
+    /// incomplete UTF-8 sequence is escaped byte-by-byte.
+    pub fn finish(self) -> Cow<'static, str> {
+        Cow::Owned(bytes_to_str(&self.pending).into_owned())
+    }
+}
+
+/// Incremental version of [`str_to_bytes`] for encoded data arriving in
+/// arbitrary pieces.
+///
+/// Feed each piece to [`push`](Self::push) and call [`finish`](Self::finish)
+/// at end of stream. The concatenation of every returned fragment is
+/// byte-identical to calling [`str_to_bytes`] on the full input at once.
+///
+/// ```
+/// # use ef80escape::{Encoder, Decoder};
+/// let data = b"\xffabc\xe4\xb8\xad";
+/// let encoded = ef80escape::bytes_to_str(data).into_owned();
+/// let bytes = encoded.as_bytes();
+/// let mut dec = Decoder::new();
+/// let mut out = Vec::new();
+/// out.extend_from_slice(&dec.push(&bytes[..1]));
+/// out.extend_from_slice(&dec.push(&bytes[1..4]));
+/// out.extend_from_slice(&dec.push(&bytes[4..]));
+/// out.extend_from_slice(&dec.finish());
+/// assert_eq!(out, data);
+/// ```
+///
+/// # Retained bytes
+///
+/// The encoded stream is UTF-8 that may be split mid-character by a transport.
+/// The decoder retains at most **3** trailing bytes — an incomplete UTF-8
+/// sequence (which includes an incomplete `0xEE b1 b2` triple) — until the
+/// next chunk completes it. The `U+EF00` escape state is likewise carried
+/// across [`push`](Self::push) calls.
+#[derive(Clone, Debug, Default)]
+pub struct Decoder {
+    pending: Vec<u8>,
+    escaped: bool,
+}
+
+impl Decoder {
+    /// Creates a decoder with no retained bytes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds the next `chunk` of encoded bytes and returns the decoded
+    /// fragment that can be resolved so far. Up to 3 trailing bytes may be
+    /// retained.
+    pub fn push<'a>(&mut self, chunk: &'a [u8]) -> Cow<'a, [u8]> {
+        if self.pending.is_empty() && !self.escaped {
+            let n = chunk.len() - incomplete_tail_len(chunk);
+            let seg = &chunk[..n];
+            if !seg.contains(&0xee) {
+                self.pending.extend_from_slice(&chunk[n..]);
+                return Cow::Borrowed(seg);
+            }
+            let mut result = Vec::with_capacity(n);
+            decode_into(seg, &mut self.escaped, &mut result);
+            self.pending.extend_from_slice(&chunk[n..]);
+            return Cow::Owned(result);
+        }
+        self.pending.extend_from_slice(chunk);
+        let n = self.pending.len() - incomplete_tail_len(&self.pending);
+        let mut result = Vec::with_capacity(n);
+        decode_into(&self.pending[..n], &mut self.escaped, &mut result);
+        self.pending.drain(..n);
+        Cow::Owned(result)
+    }
+
+    /// Flushes any retained bytes and returns the final fragment.
+    pub fn finish(mut self) -> Cow<'static, [u8]> {
+        let mut result = Vec::new();
+        decode_into(&self.pending, &mut self.escaped, &mut result);
+        Cow::Owned(result)
+    }
+}
+
+/// Converts bytes to the WTF-8 representation used by Python's
+/// [`surrogateescape`](https://peps.python.org/pep-0383) error handler.
+///
+/// Valid UTF-8 passes through unchanged. Every non-UTF-8 byte `b` (`>= 128`)
+/// is mapped to the unpaired low surrogate `U+DC00 + b` (range
+/// `U+DC80`..`U+DCFF`), whose WTF-8 encoding is the three bytes `ED B2 80` ..
+/// `ED B3 BF`. Because the surrogate is unpaired, the result is WTF-8 — the
+/// representation used by [`os_str_bytes`] and std's internal `Wtf8` — and not
+/// valid UTF-8, so the return type is [`Vec<u8>`] rather than [`String`].
+///
+/// Use this to hand data to a Python process (or anything else speaking
+/// `surrogateescape`) and [`from_surrogateescape`] to read it back.
+///
+/// ```
+/// # use ef80escape::to_surrogateescape;
+/// assert_eq!(to_surrogateescape(b"abc"), b"abc");
+/// assert_eq!(to_surrogateescape(b"\x80"), b"\xed\xb2\x80");
+/// assert_eq!(to_surrogateescape(b"\xff"), b"\xed\xb3\xbf");
+/// ```
+///
+/// [`os_str_bytes`]: https://docs.rs/os_str_bytes
+pub fn to_surrogateescape(data: &[u8]) -> Vec<u8> {
+    let mut rest = data;
+    let mut result = Vec::with_capacity(data.len());
+    loop {
+        match std::str::from_utf8(rest) {
+            Ok(s) => {
+                result.extend_from_slice(s.as_bytes());
+                break;
+            }
+            Err(e) => {
+                let l = e.valid_up_to();
+                result.extend_from_slice(&rest[..l]);
+                let b = rest[l];
+                result.extend_from_slice(&[0xed, 0xb0 | (b >> 6), 0x80 | (b & 0x3f)]);
+                rest = &rest[l + 1..];
+            }
+        }
+    }
+    result
+}
+
+/// Inverse of [`to_surrogateescape`].
+///
+/// Lone low-surrogate sequences `ED B2 80` .. `ED B3 BF` (i.e.
+/// `U+DC80`..`U+DCFF`) are restored to their original byte; all other WTF-8 is
+/// left intact. This lets you round-trip data through Python JSON produced
+/// with `surrogateescape` and back into this crate's own `U+EF80` scheme.
+///
+/// ```
+/// # use ef80escape::from_surrogateescape;
+/// assert_eq!(from_surrogateescape(b"abc").as_ref(), b"abc");
+/// assert_eq!(from_surrogateescape(b"\xed\xb2\x80").as_ref(), b"\x80");
+/// assert_eq!(from_surrogateescape(b"\xed\xb3\xbf").as_ref(), b"\xff");
+/// ```
+///
+/// # Zero-copy optimization
+///
+/// If `wtf8` contains no `0xED` byte, the return value uses [`Cow::Borrowed`].
+pub fn from_surrogateescape(wtf8: &[u8]) -> Cow<'_, [u8]> {
+    if !wtf8.contains(&0xed) {
+        return Cow::Borrowed(wtf8);
+    }
+    let mut result = Vec::with_capacity(wtf8.len());
+    let mut i = 0;
+    while i < wtf8.len() {
+        let b = wtf8[i];
+        if b == 0xed {
+            if let (Some(&b1), Some(&b2)) = (wtf8.get(i + 1), wtf8.get(i + 2)) {
+                if (b1 == 0xb2 || b1 == 0xb3) && (0x80..=0xbf).contains(&b2) {
+                    result.push(((b1 & 0x03) << 6) | (b2 & 0x3f));
+                    i += 3;
+                    continue;
+                }
+            }
+        }
+        result.push(b);
+        i += 1;
+    }
+    Cow::Owned(result)
+}
+
+/// Options controlling how [`PrintableOptions::encode`] (and
+/// [`bytes_to_printable`]) renders the whitespace controls `\t`, `\n` and `\r`.
+///
+/// When a flag is `true` the control is emitted as the two-character escape
+/// (`\t`, `\n`, `\r`); when `false` the raw byte is passed through unchanged.
+/// All three default to `true`, matching [`bytes_to_printable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrintableOptions {
+    /// Escape the tab `0x09` as `\t` instead of passing it through.
+    pub tab: bool,
+    /// Escape the line feed `0x0A` as `\n` instead of passing it through.
+    pub line_feed: bool,
+    /// Escape the carriage return `0x0D` as `\r` instead of passing it through.
+    pub carriage_return: bool,
+}
+
+impl Default for PrintableOptions {
+    fn default() -> Self {
+        Self {
+            tab: true,
+            line_feed: true,
+            carriage_return: true,
+        }
+    }
+}
+
+impl PrintableOptions {
+    /// Encodes `data` to a printable string following these options. See
+    /// [`bytes_to_printable`] for the encoding rules.
+    pub fn encode(&self, data: &[u8]) -> String {
+        let mut out = String::with_capacity(data.len());
+        let mut rest = data;
+        while !rest.is_empty() {
+            match std::str::from_utf8(rest) {
+                Ok(s) => {
+                    self.push_str(s, &mut out);
+                    break;
+                }
+                Err(e) => {
+                    let l = e.valid_up_to();
+                    // safety: rest[..l] is valid utf-8 per `valid_up_to`.
+                    self.push_str(unsafe { std::str::from_utf8_unchecked(&rest[..l]) }, &mut out);
+                    push_hex(rest[l], &mut out);
+                    rest = &rest[l + 1..];
+                }
+            }
+        }
+        out
+    }
+
+    fn push_str(&self, s: &str, out: &mut String) {
+        for c in s.chars() {
+            match c {
+                '\\' => out.push_str("\\\\"),
+                '\t' => out.push_str(if self.tab { "\\t" } else { "\t" }),
+                '\n' => out.push_str(if self.line_feed { "\\n" } else { "\n" }),
+                '\r' => out.push_str(if self.carriage_return { "\\r" } else { "\r" }),
+                c if ('\u{20}'..='\u{7e}').contains(&c) => out.push(c),
+                c if (c as u32) < 0x20 || c == '\u{7f}' => push_hex(c as u8, out),
+                c => out.push(c),
+            }
+        }
+    }
+}
+
+fn push_hex(b: u8, out: &mut String) {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    out.push('\\');
+    out.push('x');
+    out.push(HEX[(b >> 4) as usize] as char);
+    out.push(HEX[(b & 0xf) as usize] as char);
+}
+
+fn hex_val(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Error returned by [`printable_to_bytes`] on a malformed escape.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrintableError {
+    /// A `\` was followed by an unknown escape letter or by nothing. The field
+    /// is the byte offset of the `\`.
+    InvalidEscape(usize),
+    /// A `\x` escape was not followed by two hexadecimal digits. The field is
+    /// the byte offset of the `\`.
+    InvalidHex(usize),
+}
+
+impl std::fmt::Display for PrintableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrintableError::InvalidEscape(pos) => write!(f, "invalid escape at byte {}", pos),
+            PrintableError::InvalidHex(pos) => write!(f, "invalid \\x escape at byte {}", pos),
+        }
+    }
+}
+
+impl std::error::Error for PrintableError {}
+
+/// Converts bytes to a copy-pasteable, printable string, modeled on the
+/// [STFU8](https://docs.rs/stfu8) approach.
+///
+/// Unlike [`bytes_to_str`], whose Private Use Area output is invisible in
+/// terminals and diffs, this produces a lossless-but-human-readable form
+/// useful for logs, test fixtures and config files:
+///
+/// * Visible ASCII `0x20..=0x7E` passes through, except `\` which is doubled.
+/// * Controls `0x00..=0x1F`, `0x7F`, and non-UTF-8 bytes `>= 0x80` become
+///   `\xHH` with uppercase hex.
+/// * Valid multi-byte UTF-8 sequences pass through as their real characters,
+///   so genuine text stays readable rather than being buried in `\xHH`. The
+///   output is therefore printable but not necessarily pure ASCII; only the
+///   bytes that cannot form valid UTF-8 are escaped.
+///
+/// `\t`, `\n` and `\r` are escaped as `\t`, `\n`, `\r` by default; use
+/// [`PrintableOptions`] to pass them through raw instead.
+///
+/// ```
+/// # use ef80escape::bytes_to_printable;
+/// assert_eq!(bytes_to_printable(b"a\tb\xff\xe4\xb8\xad"), "a\\tb\\xFF中");
+/// ```
+pub fn bytes_to_printable(data: &[u8]) -> String {
+    PrintableOptions::default().encode(data)
+}
+
+/// Inverse of [`bytes_to_printable`].
+///
+/// Reverses `\\`, `\t`, `\n`, `\r` and `\xHH` (hex digits of either case), and
+/// passes every other character through as its UTF-8 bytes. Errors on a
+/// malformed escape.
+///
+/// ```
+/// # use ef80escape::printable_to_bytes;
+/// assert_eq!(printable_to_bytes("a\\tb\\xFF中").unwrap(), b"a\tb\xff\xe4\xb8\xad");
+/// assert!(printable_to_bytes("\\xZZ").is_err());
+/// ```
+pub fn printable_to_bytes(s: &str) -> Result<Vec<u8>, PrintableError> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == b'\\' {
+            match bytes.get(i + 1) {
+                Some(b'\\') => out.push(b'\\'),
+                Some(b't') => out.push(b'\t'),
+                Some(b'n') => out.push(b'\n'),
+                Some(b'r') => out.push(b'\r'),
+                Some(b'x') => {
+                    match (
+                        bytes.get(i + 2).copied().and_then(hex_val),
+                        bytes.get(i + 3).copied().and_then(hex_val),
+                    ) {
+                        (Some(h), Some(l)) => {
+                            out.push((h << 4) | l);
+                            i += 4;
+                            continue;
+                        }
+                        _ => return Err(PrintableError::InvalidHex(i)),
+                    }
+                }
+                _ => return Err(PrintableError::InvalidEscape(i)),
+            }
+            i += 2;
+        } else {
+            out.push(b);
+            i += 1;
+        }
+    }
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,6 +837,145 @@ mod tests {
         }
     }
 
+    fn check_streaming(data: &[u8]) {
+        let oneshot = bytes_to_str(data);
+        for step in 1..=4 {
+            let mut enc = Encoder::new();
+            let mut encoded = String::new();
+            for chunk in data.chunks(step) {
+                encoded.push_str(&enc.push(chunk));
+            }
+            encoded.push_str(&enc.finish());
+            assert_eq!(encoded, oneshot.as_ref(), "encode step {}", step);
+
+            let bytes = encoded.as_bytes();
+            let mut dec = Decoder::new();
+            let mut decoded = Vec::new();
+            for chunk in bytes.chunks(step) {
+                decoded.extend_from_slice(&dec.push(chunk));
+            }
+            decoded.extend_from_slice(&dec.finish());
+            assert_eq!(decoded, data, "decode step {}, str: {:?}", step, bytes);
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        check_streaming(b"");
+        check_streaming(b"abcd  efg");
+        check_streaming("🤦🏼‍♂️".as_bytes());
+        check_streaming("[字符 编码]".as_bytes());
+        check_streaming(b"\xffa\xfe\xfdb\xfc");
+        check_streaming(b"\0\x01\x02\xe0\xe9de\0");
+        check_streaming("\u{ef00}a\u{ef00}\u{ef00}88".as_bytes());
+        check_streaming("\u{ef00}\u{efff}\u{ef00}\u{ef80}\u{ef81}".as_bytes());
+    }
+
+    fn check_surrogateescape(data: &[u8]) {
+        let wtf8 = to_surrogateescape(data);
+        let back = from_surrogateescape(&wtf8);
+        assert_eq!(data, back.as_ref(), "wtf8: {:?}", wtf8);
+    }
+
+    #[test]
+    fn surrogateescape_round_trip() {
+        check_surrogateescape(b"");
+        check_surrogateescape(b"abcd  efg");
+        check_surrogateescape("🤦🏼‍♂️".as_bytes());
+        check_surrogateescape("[字符 编码]".as_bytes());
+        check_surrogateescape(b"\xffa\xfe\xfdb\xfc");
+        check_surrogateescape(b"\0\x01\x02\xe0\xe9de\0");
+        for b in 0..=255u8 {
+            check_surrogateescape(&[b]);
+            check_surrogateescape(&[b, b]);
+        }
+    }
+
+    fn check_printable(data: &[u8]) {
+        let s = bytes_to_printable(data);
+        let d = printable_to_bytes(&s).unwrap();
+        assert_eq!(data, d.as_slice(), "printable: {:?}", s);
+    }
+
+    #[test]
+    fn printable_round_trip() {
+        check_printable(b"");
+        check_printable(b"abcd  efg");
+        check_printable("🤦🏼‍♂️".as_bytes());
+        check_printable("[字符 编码]".as_bytes());
+        check_printable(b"\xffa\xfe\xfdb\xfc");
+        check_printable(b"\0\x01\x02\xe0\xe9de\0");
+        check_printable(b"a\\b\tc\nd\re");
+    }
+
+    #[test]
+    fn printable_passes_through_valid_utf8() {
+        // Valid multi-byte UTF-8 stays readable; only non-UTF-8 bytes escape.
+        assert_eq!(bytes_to_printable("[字符 编码]".as_bytes()), "[字符 编码]");
+        assert_eq!(bytes_to_printable(b"a\tb\xff\xe4\xb8\xad"), "a\\tb\\xFF中");
+    }
+
+    #[test]
+    fn printable_passthrough_whitespace() {
+        let opts = PrintableOptions {
+            tab: false,
+            line_feed: false,
+            carriage_return: false,
+        };
+        assert_eq!(opts.encode(b"a\tb\nc\rd"), "a\tb\nc\rd");
+        assert_eq!(printable_to_bytes(&opts.encode(b"a\tb\nc\rd")).unwrap(), b"a\tb\nc\rd");
+    }
+
+    #[test]
+    fn printable_errors() {
+        assert_eq!(printable_to_bytes("\\"), Err(PrintableError::InvalidEscape(0)));
+        assert_eq!(printable_to_bytes("ab\\q"), Err(PrintableError::InvalidEscape(2)));
+        assert_eq!(printable_to_bytes("\\xZZ"), Err(PrintableError::InvalidHex(0)));
+        assert_eq!(printable_to_bytes("\\x1"), Err(PrintableError::InvalidHex(0)));
+    }
+
+    #[test]
+    fn chunks_classification() {
+        let got: Vec<Chunk> = chunks(b"a\xff\xe4\xb8\xad").collect();
+        assert_eq!(
+            got,
+            vec![Chunk::Valid("a"), Chunk::Escape(0xff), Chunk::Valid("中")]
+        );
+
+        let got: Vec<Chunk> = chunks("x\u{efff}y".as_bytes()).collect();
+        assert_eq!(
+            got,
+            vec![Chunk::Valid("x"), Chunk::Conflict("\u{efff}"), Chunk::Valid("y")]
+        );
+    }
+
+    #[test]
+    fn chunks_matches_bytes_to_str() {
+        let check = |data: &[u8]| {
+            let mut out = String::new();
+            for chunk in chunks(data) {
+                match chunk {
+                    Chunk::Valid(s) => out.push_str(s),
+                    Chunk::Escape(b) => {
+                        out.push(char::from_u32(0xef00 | b as u32).unwrap());
+                    }
+                    Chunk::Conflict(s) => {
+                        out.push('\u{ef00}');
+                        out.push_str(s);
+                    }
+                }
+            }
+            assert_eq!(out, bytes_to_str(data).as_ref(), "data: {:?}", data);
+        };
+        check(b"");
+        check(b"abcd  efg");
+        check("🤦🏼‍♂️".as_bytes());
+        check(b"\xffa\xfe\xfdb\xfc");
+        check(b"\0\x01\x02\xe0\xe9de\0");
+        check("\u{ef00}a\u{ef00}\u{ef00}88".as_bytes());
+        check("\u{ef00}\u{efff}\u{ef00}\u{ef80}\u{ef81}".as_bytes());
+    }
+
     #[test]
     fn zero_copy() {
         let s = "123 汉字 🤦🏼‍♂️";